@@ -1,3 +1,4 @@
+use crate::keyboard::key;
 use crate::keyboard::{Key, Location, Modifiers};
 use crate::SmolStr;
 
@@ -14,6 +15,12 @@ pub enum Event {
         /// The key pressed.
         key: Key,
 
+        /// The physical key pressed.
+        physical_key: key::Physical,
+
+        /// The raw scancode of the key pressed, if the platform provides it.
+        scancode: Option<u32>,
+
         /// The location of the key.
         location: Location,
 
@@ -29,6 +36,12 @@ pub enum Event {
         /// The key released.
         key: Key,
 
+        /// The physical key released.
+        physical_key: key::Physical,
+
+        /// The raw scancode of the key released, if the platform provides it.
+        scancode: Option<u32>,
+
         /// The location of the key.
         location: Location,
 
@@ -244,3 +244,195 @@ pub fn scope<T: 'static>(
         operation: Box::new(operation),
     }
 }
+
+/// Produces an [`Operation`] that returns the bounds of the widget with the
+/// given [`Id`], if found.
+///
+/// The bounds are given in window coordinates and take into account the
+/// translation of any ancestor scrollables. Only widgets that expose their
+/// [`Id`] as a container (e.g. `Container`, `Scrollable` or `TextInput`) can
+/// be found.
+pub fn bounds(target: Id) -> impl Operation<Option<Rectangle>> {
+    struct Bounds {
+        target: Id,
+        depth: usize,
+        scrollables: Vec<(Vector, usize)>,
+        bounds: Option<Rectangle>,
+    }
+
+    impl Operation<Option<Rectangle>> for Bounds {
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            translation: Vector,
+        ) {
+            let parent_translation = self
+                .scrollables
+                .last()
+                .map(|(translation, _)| *translation)
+                .unwrap_or(Vector::ZERO);
+
+            self.scrollables
+                .push((parent_translation + translation, self.depth));
+        }
+
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<Rectangle>>,
+            ),
+        ) {
+            if self.bounds.is_some() {
+                return;
+            }
+
+            if id == Some(&self.target) {
+                // A scrollable is not translated by its own offset
+                let translation = self
+                    .scrollables
+                    .iter()
+                    .rev()
+                    .find(|(_, depth)| *depth < self.depth)
+                    .map(|(translation, _)| *translation)
+                    .unwrap_or(Vector::ZERO);
+
+                self.bounds = Some(bounds - translation);
+
+                return;
+            }
+
+            self.depth += 1;
+
+            operate_on_children(self);
+
+            self.depth -= 1;
+
+            match self.scrollables.last() {
+                Some((_, depth)) if self.depth == *depth => {
+                    let _ = self.scrollables.pop();
+                }
+                _ => {}
+            }
+        }
+
+        fn finish(&self) -> Outcome<Option<Rectangle>> {
+            Outcome::Some(self.bounds)
+        }
+    }
+
+    Bounds {
+        target,
+        depth: 0,
+        scrollables: Vec::new(),
+        bounds: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::operation::scrollable::{
+        AbsoluteOffset, RelativeOffset,
+    };
+
+    struct State;
+
+    impl Scrollable for State {
+        fn snap_to(&mut self, _offset: RelativeOffset) {}
+
+        fn scroll_to(&mut self, _offset: AbsoluteOffset) {}
+    }
+
+    type Children<'a> =
+        &'a mut dyn FnMut(&mut dyn Operation<Option<Rectangle>>);
+
+    fn rectangle(x: f32, y: f32) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width: 100.0,
+            height: 100.0,
+        }
+    }
+
+    fn scrollable(
+        operation: &mut dyn Operation<Option<Rectangle>>,
+        id: &str,
+        bounds: Rectangle,
+        translation: Vector,
+        children: Children<'_>,
+    ) {
+        let id = Id::new(id.to_owned());
+
+        operation.scrollable(&mut State, Some(&id), bounds, translation);
+        operation.container(Some(&id), bounds, children);
+    }
+
+    fn container(
+        operation: &mut dyn Operation<Option<Rectangle>>,
+        id: &str,
+        bounds: Rectangle,
+    ) {
+        operation.container(Some(&Id::new(id.to_owned())), bounds, &mut |_| {});
+    }
+
+    fn find(id: &str) -> Option<Rectangle> {
+        let mut operation = bounds(Id::new(id.to_owned()));
+
+        operation.container(None, rectangle(0.0, 0.0), &mut |operation| {
+            scrollable(
+                operation,
+                "sibling",
+                rectangle(0.0, 0.0),
+                Vector::new(0.0, 1000.0),
+                &mut |operation| {
+                    container(operation, "hidden", rectangle(0.0, 1200.0));
+                },
+            );
+
+            scrollable(
+                operation,
+                "outer",
+                rectangle(0.0, 100.0),
+                Vector::new(0.0, 100.0),
+                &mut |operation| {
+                    scrollable(
+                        operation,
+                        "inner",
+                        rectangle(0.0, 300.0),
+                        Vector::new(10.0, 50.0),
+                        &mut |operation| {
+                            container(
+                                operation,
+                                "nested",
+                                rectangle(20.0, 500.0),
+                            );
+                        },
+                    );
+
+                    container(operation, "after", rectangle(0.0, 700.0));
+                },
+            );
+        });
+
+        match operation.finish() {
+            Outcome::Some(bounds) => bounds,
+            _ => panic!("bounds operation should produce an outcome"),
+        }
+    }
+
+    #[test]
+    fn bounds_in_nested_scrollables() {
+        assert_eq!(find("sibling"), Some(rectangle(0.0, 0.0)));
+        assert_eq!(find("hidden"), Some(rectangle(0.0, 200.0)));
+        assert_eq!(find("outer"), Some(rectangle(0.0, 100.0)));
+        assert_eq!(find("inner"), Some(rectangle(0.0, 200.0)));
+        assert_eq!(find("nested"), Some(rectangle(10.0, 350.0)));
+        assert_eq!(find("after"), Some(rectangle(0.0, 600.0)));
+        assert_eq!(find("missing"), None);
+    }
+}
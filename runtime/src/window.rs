@@ -8,6 +8,7 @@ pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
 use crate::core::time::Instant;
+use crate::core::widget;
 use crate::core::window::{
     Event, Icon, Id, Level, Mode, Settings, UserAttention,
 };
@@ -215,3 +216,17 @@ pub fn screenshot<Message>(
         Box::new(f),
     )))
 }
+
+/// Runs a widget [`Operation`] on the user interface of the window with the
+/// given [`Id`].
+///
+/// [`Operation`]: widget::Operation
+pub fn operate<Message>(
+    id: Id,
+    operation: impl widget::Operation<Message> + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Operate(
+        id,
+        Box::new(operation),
+    )))
+}
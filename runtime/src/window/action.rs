@@ -1,3 +1,4 @@
+use crate::core::widget;
 use crate::core::window::{Icon, Id, Level, Mode, Settings, UserAttention};
 use crate::core::{Point, Size};
 use crate::futures::MaybeSend;
@@ -109,6 +110,8 @@ pub enum Action<T> {
     RunWithHandle(Id, Box<dyn FnOnce(WindowHandle<'_>) -> T + 'static>),
     /// Screenshot the viewport of the window.
     Screenshot(Id, Box<dyn FnOnce(Screenshot) -> T + 'static>),
+    /// Runs a widget operation on the user interface of the window.
+    Operate(Id, Box<dyn widget::Operation<T>>),
 }
 
 impl<T> Action<T> {
@@ -118,6 +121,7 @@ impl<T> Action<T> {
         f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
     ) -> Action<A>
     where
+        A: 'static,
         T: 'static,
     {
         match self {
@@ -163,6 +167,10 @@ impl<T> Action<T> {
                 id,
                 Box::new(move |screenshot| f(tag(screenshot))),
             ),
+            Self::Operate(id, operation) => Action::Operate(
+                id,
+                Box::new(widget::operation::map(operation, f)),
+            ),
         }
     }
 }
@@ -225,6 +233,7 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::RunWithHandle({id:?})")
             }
             Self::Screenshot(id, _) => write!(f, "Action::Screenshot({id:?})"),
+            Self::Operate(id, _) => write!(f, "Action::Operate({id:?})"),
         }
     }
 }
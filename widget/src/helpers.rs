@@ -5,14 +5,14 @@ use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation;
-use crate::core::{Element, Length, Pixels, Widget};
+use crate::core::{Element, Length, Pixels, Rectangle, Widget};
 use crate::keyed;
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
 use crate::rule::{self, Rule};
-use crate::runtime::Command;
+use crate::runtime::{self, Command};
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
 use crate::text::{self, Text};
@@ -944,6 +944,22 @@ where
     Command::widget(operation::focusable::focus_next())
 }
 
+/// Queries the bounds of the widget with the given [`Id`] in the window with
+/// the given [`window::Id`].
+///
+/// The bounds are given in window coordinates and take into account the
+/// translation of any ancestor scrollables. The [`Command`] produces `None`
+/// if no widget with the given [`Id`] is found in the window.
+///
+/// [`Id`]: core::widget::Id
+/// [`window::Id`]: core::window::Id
+pub fn bounds(
+    window: core::window::Id,
+    id: impl Into<core::widget::Id>,
+) -> Command<Option<Rectangle>> {
+    runtime::window::operate(window, operation::bounds(id.into()))
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
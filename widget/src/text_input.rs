@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
@@ -10,6 +11,7 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::{Edit, History};
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
@@ -76,6 +78,7 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    primary_selection: bool,
     class: Theme::Class<'a>,
 }
 
@@ -105,6 +108,14 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            primary_selection: cfg!(all(
+                unix,
+                not(any(
+                    target_os = "macos",
+                    target_os = "ios",
+                    target_os = "android"
+                ))
+            )),
             class: Theme::default(),
         }
     }
@@ -164,6 +175,18 @@ where
         self
     }
 
+    /// Sets whether the [`TextInput`] should use the primary selection.
+    ///
+    /// When enabled, selecting text writes it to the primary clipboard and
+    /// pressing the middle mouse button inserts its contents at the cursor
+    /// position.
+    ///
+    /// By default, this is only enabled on X11 and Wayland platforms.
+    pub fn primary_selection(mut self, primary_selection: bool) -> Self {
+        self.primary_selection = primary_selection;
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -208,6 +231,49 @@ where
         self
     }
 
+    /// Writes the current selection of the [`TextInput`] to the primary
+    /// clipboard, if enabled.
+    fn write_primary(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        clipboard: &mut dyn Clipboard,
+    ) {
+        if !self.primary_selection || self.is_secure {
+            return;
+        }
+
+        if let Some((start, end)) = state.cursor.selection(&self.value) {
+            clipboard.write(
+                clipboard::Kind::Primary,
+                self.value.select(start, end).to_string(),
+            );
+        }
+    }
+
+    /// Finds the position in the [`Value`] of the [`TextInput`] under the
+    /// given horizontal coordinate.
+    fn cursor_position_at(
+        &self,
+        text_layout: Layout<'_>,
+        state: &State<Renderer::Paragraph>,
+        x: f32,
+    ) -> usize {
+        let target = x - text_layout.bounds().x;
+
+        if target > 0.0 {
+            let value = if self.is_secure {
+                self.value.secure()
+            } else {
+                self.value.clone()
+            };
+
+            find_cursor_position(text_layout.bounds(), &value, state, target)
+        } else {
+            None
+        }
+        .unwrap_or(0)
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -538,14 +604,16 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let id = self.id.as_ref().map(|id| &id.0);
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.container(id, layout.bounds(), &mut |_| {});
+        operation.focusable(state, id);
+        operation.text_input(state, id);
     }
 
     fn on_event(
@@ -605,29 +673,19 @@ where
 
                     match click.kind() {
                         click::Kind::Single => {
-                            let position = if target > 0.0 {
-                                let value = if self.is_secure {
-                                    self.value.secure()
-                                } else {
-                                    self.value.clone()
-                                };
-
-                                find_cursor_position(
-                                    text_layout.bounds(),
-                                    &value,
-                                    state,
-                                    target,
-                                )
-                            } else {
-                                None
-                            }
-                            .unwrap_or(0);
+                            let position = self.cursor_position_at(
+                                text_layout,
+                                state,
+                                cursor_position.x,
+                            );
 
                             if state.keyboard_modifiers.shift() {
                                 state.cursor.select_range(
                                     state.cursor.start(&self.value),
                                     position,
                                 );
+
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_to(position);
                             }
@@ -651,23 +709,83 @@ where
                                 );
                             }
 
+                            self.write_primary(state, clipboard);
                             state.is_dragging = false;
                         }
                         click::Kind::Triple => {
                             state.cursor.select_all(&self.value);
+                            self.write_primary(state, clipboard);
                             state.is_dragging = false;
                         }
                     }
 
+                    state.history.flush();
                     state.last_click = Some(click);
 
                     return event::Status::Captured;
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) if self.primary_selection => {
+                let Some(on_input) = &self.on_input else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let state = state::<Renderer>(tree);
+
+                let content: String = clipboard
+                    .read(clipboard::Kind::Primary)
+                    .unwrap_or_default()
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                if content.is_empty() {
+                    return event::Status::Captured;
+                }
+
+                let position = self.cursor_position_at(
+                    layout.children().next().unwrap(),
+                    state,
+                    cursor_position.x,
+                );
+
+                state.history.record(Edit::Paste, &self.value, state.cursor);
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+
+                editor.paste_at(position, Value::new(&content));
+
+                let message = if let Some(paste) = &self.on_paste {
+                    (paste)(editor.contents())
+                } else {
+                    (on_input)(editor.contents())
+                };
+                shell.publish(message);
+
+                state.history.commit(&self.value);
+                update_cache(state, &self.value);
+
+                return event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
-                state::<Renderer>(tree).is_dragging = false;
+                let state = state::<Renderer>(tree);
+
+                if state.is_dragging {
+                    self.write_primary(state, clipboard);
+                }
+
+                state.is_dragging = false;
             }
             Event::Mouse(mouse::Event::CursorMoved { position })
             | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
@@ -694,6 +812,7 @@ where
                     state
                         .cursor
                         .select_range(state.cursor.start(&value), position);
+                    state.history.flush();
 
                     return event::Status::Captured;
                 }
@@ -740,6 +859,17 @@ where
                                 );
                             }
 
+                            if state.cursor.selection(&self.value).is_some()
+                                || state.cursor.end(&self.value)
+                                    < self.value.len()
+                            {
+                                state.history.record(
+                                    Edit::Cut,
+                                    &self.value,
+                                    state.cursor,
+                                );
+                            }
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -747,6 +877,7 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.history.commit(&self.value);
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
@@ -769,6 +900,16 @@ where
                                 }
                             };
 
+                            if !content.is_empty()
+                                || state.cursor.selection(&self.value).is_some()
+                            {
+                                state.history.record(
+                                    Edit::Paste,
+                                    &self.value,
+                                    state.cursor,
+                                );
+                            }
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -783,14 +924,39 @@ where
 
                             state.is_pasting = Some(content);
 
+                            state.history.commit(&self.value);
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
                         }
+                        keyboard::Key::Character(key @ ("z" | "y"))
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let restored = if key == "y" || modifiers.shift() {
+                                state.history.redo(&self.value, state.cursor)
+                            } else {
+                                state.history.undo(&self.value, state.cursor)
+                            };
+
+                            if let Some((value, cursor)) = restored {
+                                self.value = value;
+                                state.cursor = cursor;
+
+                                let message =
+                                    (on_input)(self.value.to_string());
+                                shell.publish(message);
+
+                                update_cache(state, &self.value);
+                            }
+
+                            return event::Status::Captured;
+                        }
                         keyboard::Key::Character("a")
                             if state.keyboard_modifiers.command() =>
                         {
                             state.cursor.select_all(&self.value);
+                            state.history.flush();
+                            self.write_primary(state, clipboard);
 
                             return event::Status::Captured;
                         }
@@ -803,6 +969,12 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
+                            state.history.record(
+                                Edit::Insert,
+                                &self.value,
+                                state.cursor,
+                            );
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -811,6 +983,12 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.history.commit(&self.value);
+
+                            if c.is_whitespace() {
+                                state.history.flush();
+                            }
+
                             focus.updated_at = Instant::now();
 
                             update_cache(state, &self.value);
@@ -840,6 +1018,16 @@ where
                                 }
                             }
 
+                            if state.cursor.selection(&self.value).is_some()
+                                || state.cursor.start(&self.value) > 0
+                            {
+                                state.history.record(
+                                    Edit::Delete,
+                                    &self.value,
+                                    state.cursor,
+                                );
+                            }
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
@@ -847,6 +1035,7 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.history.commit(&self.value);
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
@@ -867,6 +1056,17 @@ where
                                 }
                             }
 
+                            if state.cursor.selection(&self.value).is_some()
+                                || state.cursor.end(&self.value)
+                                    < self.value.len()
+                            {
+                                state.history.record(
+                                    Edit::Delete,
+                                    &self.value,
+                                    state.cursor,
+                                );
+                            }
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -874,24 +1074,31 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.history.commit(&self.value);
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Home) => {
+                            state.history.flush();
+
                             if modifiers.shift() {
                                 state.cursor.select_range(
                                     state.cursor.start(&self.value),
                                     0,
                                 );
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_to(0);
                             }
                         }
                         keyboard::Key::Named(key::Named::End) => {
+                            state.history.flush();
+
                             if modifiers.shift() {
                                 state.cursor.select_range(
                                     state.cursor.start(&self.value),
                                     self.value.len(),
                                 );
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_to(self.value.len());
                             }
@@ -899,11 +1106,14 @@ where
                         keyboard::Key::Named(key::Named::ArrowLeft)
                             if modifiers.macos_command() =>
                         {
+                            state.history.flush();
+
                             if modifiers.shift() {
                                 state.cursor.select_range(
                                     state.cursor.start(&self.value),
                                     0,
                                 );
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_to(0);
                             }
@@ -911,21 +1121,27 @@ where
                         keyboard::Key::Named(key::Named::ArrowRight)
                             if modifiers.macos_command() =>
                         {
+                            state.history.flush();
+
                             if modifiers.shift() {
                                 state.cursor.select_range(
                                     state.cursor.start(&self.value),
                                     self.value.len(),
                                 );
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_to(self.value.len());
                             }
                         }
                         keyboard::Key::Named(key::Named::ArrowLeft) => {
+                            state.history.flush();
+
                             if modifiers.jump() && !self.is_secure {
                                 if modifiers.shift() {
                                     state
                                         .cursor
                                         .select_left_by_words(&self.value);
+                                    self.write_primary(state, clipboard);
                                 } else {
                                     state
                                         .cursor
@@ -933,16 +1149,20 @@ where
                                 }
                             } else if modifiers.shift() {
                                 state.cursor.select_left(&self.value);
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_left(&self.value);
                             }
                         }
                         keyboard::Key::Named(key::Named::ArrowRight) => {
+                            state.history.flush();
+
                             if modifiers.jump() && !self.is_secure {
                                 if modifiers.shift() {
                                     state
                                         .cursor
                                         .select_right_by_words(&self.value);
+                                    self.write_primary(state, clipboard);
                                 } else {
                                     state
                                         .cursor
@@ -950,6 +1170,7 @@ where
                                 }
                             } else if modifiers.shift() {
                                 state.cursor.select_right(&self.value);
+                                self.write_primary(state, clipboard);
                             } else {
                                 state.cursor.move_right(&self.value);
                             }
@@ -1183,6 +1404,7 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1217,6 +1439,7 @@ impl<P: text::Paragraph> State<P> {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            history: History::default(),
         }
     }
 
@@ -1251,21 +1474,25 @@ impl<P: text::Paragraph> State<P> {
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     pub fn move_cursor_to_front(&mut self) {
         self.cursor.move_to(0);
+        self.history.flush();
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the end of the input text.
     pub fn move_cursor_to_end(&mut self) {
         self.cursor.move_to(usize::MAX);
+        self.history.flush();
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to an arbitrary location.
     pub fn move_cursor_to(&mut self, position: usize) {
         self.cursor.move_to(position);
+        self.history.flush();
     }
 
     /// Selects all the content of the [`TextInput`].
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
+        self.history.flush();
     }
 }
 
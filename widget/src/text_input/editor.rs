@@ -36,6 +36,16 @@ impl<'a> Editor<'a> {
         self.cursor.move_right_by_amount(self.value, length);
     }
 
+    pub fn paste_at(&mut self, position: usize, content: Value) {
+        let length = content.len();
+        let position = position.min(self.value.len());
+
+        self.value.insert_many(position, content);
+
+        self.cursor.move_to(position);
+        self.cursor.move_right_by_amount(self.value, length);
+    }
+
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
@@ -0,0 +1,415 @@
+use crate::core::time::{Duration, Instant};
+use crate::text_input::{Cursor, Value};
+
+/// The maximum amount of entries kept in the undo stack.
+const MAX_ENTRIES: usize = 100;
+
+/// The time after which consecutive edits stop being grouped together.
+const GROUP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The undo and redo stacks of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    last_edit: Option<(Edit, Instant)>,
+    current: Option<Value>,
+    pending: Option<Pending>,
+}
+
+/// A kind of edit applied to a text input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Insert,
+    Delete,
+    Paste,
+    Cut,
+}
+
+impl Edit {
+    fn is_groupable(self) -> bool {
+        matches!(self, Self::Insert | Self::Delete)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+/// The last recorded edit, kept until the application reports back.
+#[derive(Debug, Clone)]
+struct Pending {
+    /// The [`Value`] right before the edit.
+    before: Value,
+    /// Whether the edit started a new undo unit.
+    is_new_entry: bool,
+}
+
+impl History {
+    /// Records the state of a text input right before applying an [`Edit`].
+    ///
+    /// Consecutive groupable edits of the same kind are merged into a single
+    /// undo unit until [`History::flush`] is called or the edits are more
+    /// than a second apart.
+    pub fn record(&mut self, edit: Edit, value: &Value, cursor: Cursor) {
+        self.sync(value);
+
+        let now = Instant::now();
+
+        let is_grouped = self.last_edit.is_some_and(|(last, at)| {
+            last == edit && edit.is_groupable() && now - at < GROUP_TIMEOUT
+        });
+
+        if !is_grouped {
+            if self.undo.len() == MAX_ENTRIES {
+                let _ = self.undo.remove(0);
+            }
+
+            self.undo.push(Entry {
+                value: value.clone(),
+                cursor,
+            });
+        }
+
+        self.redo.clear();
+        self.last_edit = Some((edit, now));
+        self.pending = Some(Pending {
+            before: value.clone(),
+            is_new_entry: !is_grouped,
+        });
+    }
+
+    /// Stores the [`Value`] produced by the last recorded edit.
+    pub fn commit(&mut self, value: &Value) {
+        self.current = Some(value.clone());
+    }
+
+    /// Ends the current group of edits, if any.
+    pub fn flush(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Reverts the last undo unit, returning the previous [`Value`] and
+    /// [`Cursor`].
+    pub fn undo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        self.sync(value);
+
+        let entry = self.undo.pop()?;
+
+        self.redo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        Some(self.restore(entry))
+    }
+
+    /// Reapplies the last undone unit, returning the resulting [`Value`] and
+    /// [`Cursor`].
+    pub fn redo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        self.sync(value);
+
+        let entry = self.redo.pop()?;
+
+        self.undo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        Some(self.restore(entry))
+    }
+
+    fn restore(&mut self, entry: Entry) -> (Value, Cursor) {
+        self.last_edit = None;
+        self.current = Some(entry.value.clone());
+
+        (entry.value, entry.cursor)
+    }
+
+    /// Reconciles the [`History`] with the [`Value`] currently provided by
+    /// the application.
+    ///
+    /// If the application rejected the last edit, only that edit is dropped.
+    /// If it rewrote the outcome of the last edit (e.g. by uppercasing it),
+    /// the rewritten [`Value`] is treated as the outcome of that edit. Any
+    /// other external change keeps the undo stack, but ends the current group
+    /// and clears the redo stack, since those edits no longer apply.
+    fn sync(&mut self, value: &Value) {
+        let pending = self.pending.take();
+
+        match &self.current {
+            Some(current) if current != value => {
+                match pending {
+                    Some(pending) if &pending.before == value => {
+                        if pending.is_new_entry {
+                            let _ = self.undo.pop();
+                            self.last_edit = None;
+                        }
+                    }
+                    Some(_) => {}
+                    None => {
+                        self.redo.clear();
+                        self.last_edit = None;
+                    }
+                }
+
+                self.current = Some(value.clone());
+            }
+            Some(_) => {}
+            None => {
+                self.current = Some(value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(
+        history: &mut History,
+        kind: Edit,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        result: &str,
+    ) {
+        history.record(kind, value, *cursor);
+
+        *value = Value::new(result);
+        cursor.move_to(value.len());
+
+        history.commit(value);
+    }
+
+    fn undo(history: &mut History, value: &mut Value, cursor: &mut Cursor) {
+        let (new_value, new_cursor) =
+            history.undo(value, *cursor).expect("Undo entry");
+
+        *value = new_value;
+        *cursor = new_cursor;
+    }
+
+    fn redo(history: &mut History, value: &mut Value, cursor: &mut Cursor) {
+        let (new_value, new_cursor) =
+            history.redo(value, *cursor).expect("Redo entry");
+
+        *value = new_value;
+        *cursor = new_cursor;
+    }
+
+    #[test]
+    fn undo_and_redo_every_edit() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "abc");
+        edit(&mut history, Edit::Cut, &mut value, &mut cursor, "ab");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "abd");
+        history.flush();
+        edit(&mut history, Edit::Delete, &mut value, &mut cursor, "ab");
+
+        for expected in ["abd", "ab", "abc", "a", ""] {
+            undo(&mut history, &mut value, &mut cursor);
+            assert_eq!(value, Value::new(expected));
+        }
+
+        assert!(history.undo(&value, cursor).is_none());
+
+        for expected in ["a", "abc", "ab", "abd", "ab"] {
+            redo(&mut history, &mut value, &mut cursor);
+            assert_eq!(value, Value::new(expected));
+        }
+
+        assert!(history.redo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn groups_edits_until_flush() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab");
+        history.flush();
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab ");
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab c");
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new("ab"));
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new(""));
+    }
+
+    #[test]
+    fn moving_the_cursor_ends_group() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "abc");
+
+        // The cursor moves to the front
+        cursor.move_to(0);
+        history.flush();
+
+        history.record(Edit::Insert, &value, cursor);
+        value = Value::new("xabc");
+        cursor.move_to(1);
+        history.commit(&value);
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new("abc"));
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new(""));
+    }
+
+    #[test]
+    fn does_not_group_different_edits() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab");
+        edit(&mut history, Edit::Delete, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "ax");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "axx");
+
+        for expected in ["ax", "a", "ab", ""] {
+            undo(&mut history, &mut value, &mut cursor);
+            assert_eq!(value, Value::new(expected));
+        }
+    }
+
+    #[test]
+    fn new_edit_clears_redo() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "a");
+        undo(&mut history, &mut value, &mut cursor);
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "b");
+
+        assert!(history.redo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn drops_oldest_entry_at_limit() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for i in 0..=MAX_ENTRIES {
+            let result = format!("{}{i}", value);
+
+            edit(&mut history, Edit::Paste, &mut value, &mut cursor, &result);
+        }
+
+        for _ in 0..MAX_ENTRIES {
+            undo(&mut history, &mut value, &mut cursor);
+        }
+
+        assert_eq!(value, Value::new("0"));
+        assert!(history.undo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn rejected_edit_only_drops_itself() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "ab");
+
+        // The application rejects the last edit
+        history.record(Edit::Paste, &value, cursor);
+        history.commit(&Value::new("abc"));
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "abd");
+
+        for expected in ["ab", "a", ""] {
+            undo(&mut history, &mut value, &mut cursor);
+            assert_eq!(value, Value::new(expected));
+        }
+
+        assert!(history.undo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn rejected_grouped_edit_keeps_group() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "a");
+
+        // The application rejects the second keystroke of the group
+        history.record(Edit::Insert, &value, cursor);
+        history.commit(&Value::new("a1"));
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab");
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new(""));
+        assert!(history.undo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn rewritten_edit_keeps_history() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        // The application uppercases every edit
+        for (typed, rewritten) in [("a", "A"), ("Ab", "AB"), ("ABc", "ABC")] {
+            history.record(Edit::Insert, &value, cursor);
+            history.commit(&Value::new(typed));
+
+            value = Value::new(rewritten);
+        }
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "ABCD");
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new("ABC"));
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new(""));
+    }
+
+    #[test]
+    fn external_change_clears_redo() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "ab");
+        undo(&mut history, &mut value, &mut cursor);
+
+        // The application replaces the value on its own
+        value = Value::new("xyz");
+
+        assert!(history.redo(&value, cursor).is_none());
+
+        undo(&mut history, &mut value, &mut cursor);
+        assert_eq!(value, Value::new(""));
+    }
+}
@@ -1074,6 +1074,17 @@ pub fn run_command<A, C, E>(
                         state.viewport().scale_factor(),
                     )));
                 }
+                window::Action::Operate(_id, action) => {
+                    run_operation(
+                        application,
+                        action,
+                        cache,
+                        state,
+                        renderer,
+                        proxy,
+                        debug,
+                    );
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -1094,33 +1105,15 @@ pub fn run_command<A, C, E>(
                 }
             },
             command::Action::Widget(action) => {
-                let mut current_cache = std::mem::take(cache);
-                let mut current_operation = Some(action);
-
-                let mut user_interface = build_user_interface(
+                run_operation(
                     application,
-                    current_cache,
+                    action,
+                    cache,
+                    state,
                     renderer,
-                    state.logical_size(),
+                    proxy,
                     debug,
                 );
-
-                while let Some(mut operation) = current_operation.take() {
-                    user_interface.operate(renderer, operation.as_mut());
-
-                    match operation.finish() {
-                        operation::Outcome::None => {}
-                        operation::Outcome::Some(message) => {
-                            proxy.send(message);
-                        }
-                        operation::Outcome::Chain(next) => {
-                            current_operation = Some(next);
-                        }
-                    }
-                }
-
-                current_cache = user_interface.into_cache();
-                *cache = current_cache;
             }
             command::Action::LoadFont { bytes, tagger } => {
                 // TODO: Error handling (?)
@@ -1134,3 +1127,45 @@ pub fn run_command<A, C, E>(
         }
     }
 }
+
+/// Runs a widget [`Operation`] on the user interface of the [`Application`].
+///
+/// [`Operation`]: operation::Operation
+fn run_operation<A>(
+    application: &A,
+    operation: Box<dyn operation::Operation<A::Message>>,
+    cache: &mut user_interface::Cache,
+    state: &State<A>,
+    renderer: &mut A::Renderer,
+    proxy: &mut Proxy<A::Message>,
+    debug: &mut Debug,
+) where
+    A: Application,
+    A::Theme: DefaultStyle,
+{
+    let mut current_operation = Some(operation);
+
+    let mut user_interface = build_user_interface(
+        application,
+        std::mem::take(cache),
+        renderer,
+        state.logical_size(),
+        debug,
+    );
+
+    while let Some(mut operation) = current_operation.take() {
+        user_interface.operate(renderer, operation.as_mut());
+
+        match operation.finish() {
+            operation::Outcome::None => {}
+            operation::Outcome::Some(message) => {
+                proxy.send(message);
+            }
+            operation::Outcome::Chain(next) => {
+                current_operation = Some(next);
+            }
+        }
+    }
+
+    *cache = user_interface.into_cache();
+}
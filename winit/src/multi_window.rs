@@ -1249,6 +1249,17 @@ fn run_command<A, C, E>(
                         )));
                     }
                 }
+                window::Action::Operate(id, action) => {
+                    run_operation(
+                        application,
+                        action,
+                        Some(id),
+                        proxy,
+                        debug,
+                        window_manager,
+                        ui_caches,
+                    );
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -1269,35 +1280,15 @@ fn run_command<A, C, E>(
                 }
             },
             command::Action::Widget(action) => {
-                let mut current_operation = Some(action);
-
-                let mut uis = build_user_interfaces(
+                run_operation(
                     application,
+                    action,
+                    None,
+                    proxy,
                     debug,
                     window_manager,
-                    std::mem::take(ui_caches),
+                    ui_caches,
                 );
-
-                while let Some(mut operation) = current_operation.take() {
-                    for (id, ui) in uis.iter_mut() {
-                        if let Some(window) = window_manager.get_mut(*id) {
-                            ui.operate(&window.renderer, operation.as_mut());
-                        }
-                    }
-
-                    match operation.finish() {
-                        operation::Outcome::None => {}
-                        operation::Outcome::Some(message) => {
-                            proxy.send(message);
-                        }
-                        operation::Outcome::Chain(next) => {
-                            current_operation = Some(next);
-                        }
-                    }
-                }
-
-                *ui_caches =
-                    uis.drain().map(|(id, ui)| (id, ui.into_cache())).collect();
             }
             command::Action::LoadFont { bytes, tagger } => {
                 // TODO: Error handling (?)
@@ -1312,6 +1303,57 @@ fn run_command<A, C, E>(
     }
 }
 
+/// Runs a widget [`Operation`] on the user interface of the window with the
+/// given [`window::Id`], or of every window if none is given.
+///
+/// [`Operation`]: operation::Operation
+fn run_operation<A, C>(
+    application: &A,
+    operation: Box<dyn operation::Operation<A::Message>>,
+    target: Option<window::Id>,
+    proxy: &mut Proxy<A::Message>,
+    debug: &mut Debug,
+    window_manager: &mut WindowManager<A, C>,
+    ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    A::Theme: DefaultStyle,
+{
+    let mut current_operation = Some(operation);
+
+    let mut uis = build_user_interfaces(
+        application,
+        debug,
+        window_manager,
+        std::mem::take(ui_caches),
+    );
+
+    while let Some(mut operation) = current_operation.take() {
+        for (id, ui) in uis.iter_mut() {
+            if target.is_some_and(|target| target != *id) {
+                continue;
+            }
+
+            if let Some(window) = window_manager.get_mut(*id) {
+                ui.operate(&window.renderer, operation.as_mut());
+            }
+        }
+
+        match operation.finish() {
+            operation::Outcome::None => {}
+            operation::Outcome::Some(message) => {
+                proxy.send(message);
+            }
+            operation::Outcome::Chain(next) => {
+                current_operation = Some(next);
+            }
+        }
+    }
+
+    *ui_caches = uis.drain().map(|(id, ui)| (id, ui.into_cache())).collect();
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, A: Application, C>(
     application: &'a A,